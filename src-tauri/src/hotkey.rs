@@ -0,0 +1,120 @@
+//! Global hotkey to summon the vault window from anywhere in the OS.
+//!
+//! The chosen accelerator is persisted under the app data dir so it survives
+//! restarts, and re-registering always unregisters the previous accelerator
+//! first to avoid stacking duplicate handlers on the same key. Dismissing
+//! via `Esc` is intentionally *not* a second global shortcut (see
+//! [`hide_window`]) since that would grab the key system-wide instead of
+//! just while the vault window has focus.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, GlobalShortcutManager, Manager};
+
+pub const DEFAULT_ACCELERATOR: &str = "Ctrl+Shift+Space";
+const HOTKEY_FILE: &str = "hotkey.json";
+
+/// Tracks the accelerator currently registered with the OS so we know what
+/// to unregister before binding a new one.
+pub struct HotkeyState(pub Mutex<Option<String>>);
+
+impl Default for HotkeyState {
+    fn default() -> Self {
+        HotkeyState(Mutex::new(None))
+    }
+}
+
+fn hotkey_file_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Could not get app data directory".to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(HOTKEY_FILE))
+}
+
+/// Loads the persisted accelerator, falling back to [`DEFAULT_ACCELERATOR`]
+/// if none was saved yet or the file can't be read.
+pub fn load_persisted_accelerator(app: &AppHandle) -> String {
+    hotkey_file_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .and_then(|value| value.get("accelerator").and_then(|v| v.as_str().map(String::from)))
+        .unwrap_or_else(|| DEFAULT_ACCELERATOR.to_string())
+}
+
+fn persist_accelerator(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let path = hotkey_file_path(app)?;
+    let contents = serde_json::json!({ "accelerator": accelerator }).to_string();
+    fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+/// Toggles the main window: shows and focuses it when hidden, hides it when
+/// visible. Used both by the global hotkey and the tray icon's left click.
+pub fn toggle_main_window(app: &AppHandle) -> Result<(), String> {
+    let window = app
+        .get_window("main")
+        .ok_or_else(|| "main window not found".to_string())?;
+    if window.is_visible().map_err(|e| e.to_string())? {
+        window.hide().map_err(|e| e.to_string())
+    } else {
+        window.show().map_err(|e| e.to_string())?;
+        window.set_focus().map_err(|e| e.to_string())
+    }
+}
+
+/// Registers `accelerator` to toggle the main window, unregistering whatever
+/// was previously bound so handlers never stack.
+pub fn bind_accelerator(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let state = app.state::<HotkeyState>();
+    let mut current = state.0.lock().map_err(|e| e.to_string())?;
+    let mut manager = app.global_shortcut_manager();
+
+    if let Some(previous) = current.take() {
+        let _ = manager.unregister(&previous);
+    }
+
+    let app_handle = app.clone();
+    manager
+        .register(accelerator, move || {
+            let _ = toggle_main_window(&app_handle);
+        })
+        .map_err(|e| e.to_string())?;
+
+    *current = Some(accelerator.to_string());
+    Ok(())
+}
+
+/// Hides the main window without quitting. Unlike the summon/dismiss
+/// accelerator, `Esc` must only act while the vault window itself has
+/// focus, so this is a plain command for the frontend's own `keydown`
+/// handler to call rather than another OS-wide shortcut registration
+/// (which would intercept `Esc` in every other running application).
+#[tauri::command]
+pub async fn hide_window(app: AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_window("main") {
+        window.hide().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn register_global_hotkey(app: AppHandle, accelerator: String) -> Result<(), String> {
+    bind_accelerator(&app, &accelerator)?;
+    persist_accelerator(&app, &accelerator)
+}
+
+#[tauri::command]
+pub async fn unregister_global_hotkey(app: AppHandle) -> Result<(), String> {
+    let state = app.state::<HotkeyState>();
+    let mut current = state.0.lock().map_err(|e| e.to_string())?;
+    if let Some(accelerator) = current.take() {
+        app.global_shortcut_manager()
+            .unregister(&accelerator)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
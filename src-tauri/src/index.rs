@@ -0,0 +1,180 @@
+//! Builds an in-memory index of the vault directory during startup so the
+//! frontend can render a library view as soon as the main window appears.
+
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use crate::vault;
+
+const VAULT_DIR: &str = "vault";
+const INDEX_CACHE_FILE: &str = "index-cache.json";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexedDocument {
+    pub path: String,
+    pub title: String,
+    pub modified: u64,
+    pub word_count: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct VaultIndex {
+    pub documents: Vec<IndexedDocument>,
+    pub error: Option<String>,
+}
+
+/// Holds the index built at startup so `get_vault_index` can return it
+/// without re-scanning the vault on every call.
+pub struct IndexState(pub Mutex<VaultIndex>);
+
+impl Default for IndexState {
+    fn default() -> Self {
+        IndexState(Mutex::new(VaultIndex::default()))
+    }
+}
+
+fn title_from_path(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+/// Uses the body's first non-blank line as the title, with any leading `#`
+/// heading markers stripped, falling back to the note's id (the filename
+/// stem) when the body is blank or that line is only `#` markers.
+fn title_from_body(path: &Path, body: &str) -> String {
+    body.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(|line| line.trim_start_matches('#').trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| title_from_path(path))
+}
+
+fn index_document(path: &Path) -> std::io::Result<IndexedDocument> {
+    let contents = std::fs::read_to_string(path)?;
+    let body = vault::body_only(&contents);
+    let modified = std::fs::metadata(path)?
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    Ok(IndexedDocument {
+        path: path.to_string_lossy().to_string(),
+        title: title_from_body(path, body),
+        modified,
+        word_count: body.split_whitespace().count(),
+    })
+}
+
+/// Scans the vault directory for Markdown documents and builds an index.
+/// Creates the vault directory on first use so a fresh install still has
+/// somewhere to index from.
+pub fn build_vault_index(vault_dir: &Path) -> std::io::Result<Vec<IndexedDocument>> {
+    std::fs::create_dir_all(vault_dir)?;
+
+    let mut documents = Vec::new();
+    for entry in std::fs::read_dir(vault_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) == Some("md") {
+            documents.push(index_document(&path)?);
+        }
+    }
+    Ok(documents)
+}
+
+/// Runs the startup scan, storing the result (or the error, with an empty
+/// index) in [`IndexState`] so the main window can be shown regardless.
+pub async fn run_startup_scan(app: &AppHandle) {
+    let result = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Could not get app data directory".to_string())
+        .and_then(|dir| {
+            build_vault_index(&dir.join(VAULT_DIR)).map_err(|e| e.to_string())
+        });
+
+    let index = match result {
+        Ok(documents) => VaultIndex { documents, error: None },
+        Err(error) => VaultIndex { documents: Vec::new(), error: Some(error) },
+    };
+
+    let state = app.state::<IndexState>();
+    *state.0.lock().expect("index state poisoned") = index;
+}
+
+/// Writes the in-memory index to disk so it survives the process exiting
+/// while running in the background (tray close, OS shutdown, etc).
+pub fn flush_to_disk(app: &AppHandle) -> Result<(), String> {
+    let dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Could not get app data directory".to_string())?;
+    let state = app.state::<IndexState>();
+    let index = state.0.lock().map_err(|e| e.to_string())?;
+    let contents = serde_json::to_string(&*index).map_err(|e| e.to_string())?;
+    std::fs::write(dir.join(INDEX_CACHE_FILE), contents).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_vault_index(app: AppHandle) -> Result<VaultIndex, String> {
+    let state = app.state::<IndexState>();
+    Ok(state.0.lock().map_err(|e| e.to_string())?.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn titles_from_first_heading() {
+        let path = Path::new("my-note.md");
+        assert_eq!(title_from_body(path, "# Hello World\nbody text"), "Hello World");
+        assert_eq!(title_from_body(path, "## Nested Heading\nbody"), "Nested Heading");
+    }
+
+    #[test]
+    fn titles_fall_back_to_filename_for_blank_body() {
+        let path = Path::new("my-note.md");
+        assert_eq!(title_from_body(path, ""), "my-note");
+        assert_eq!(title_from_body(path, "   \n\n  "), "my-note");
+    }
+
+    #[test]
+    fn titles_fall_back_to_filename_for_heading_only_whitespace() {
+        let path = Path::new("my-note.md");
+        assert_eq!(title_from_body(path, "#\nbody text"), "my-note");
+        assert_eq!(title_from_body(path, "#   \nbody text"), "my-note");
+    }
+
+    #[test]
+    fn titles_use_first_non_blank_line_when_not_a_heading() {
+        let path = Path::new("my-note.md");
+        assert_eq!(title_from_body(path, "\n\nJust a plain first line\nmore body"), "Just a plain first line");
+    }
+
+    #[test]
+    fn index_document_strips_front_matter_from_word_count_and_title() {
+        let dir = std::env::temp_dir().join(format!("mind-vault-index-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("recipe.md");
+        std::fs::write(
+            &path,
+            "---\ntags:\n- recipe\ncreated: 1\nmodified: 2\n---\n# Soup Recipe\nTwo cups of water.",
+        )
+        .unwrap();
+
+        let indexed = index_document(&path).unwrap();
+
+        assert_eq!(indexed.title, "Soup Recipe");
+        assert_eq!(indexed.word_count, 7);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,7 +1,17 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tauri::Manager;
+mod hotkey;
+mod index;
+mod menu;
+mod tray;
+mod updater;
+mod vault;
+
+use tauri::{Manager, RunEvent, WindowBuilder, WindowUrl};
+
+use hotkey::HotkeyState;
+use index::IndexState;
 
 // Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
 #[tauri::command]
@@ -19,7 +29,72 @@ async fn get_app_data_dir() -> Result<String, String> {
 
 fn main() {
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![greet, get_app_data_dir])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .manage(HotkeyState::default())
+        .manage(IndexState::default())
+        .menu(menu::build_menu())
+        .on_menu_event(menu::handle_menu_event)
+        .on_window_event(menu::handle_window_event)
+        .system_tray(tray::build_tray())
+        .on_system_tray_event(tray::handle_tray_event)
+        .setup(|app| {
+            let handle = app.handle();
+
+            WindowBuilder::new(app, "splashscreen", WindowUrl::App("splashscreen.html".into()))
+                .title("Mind Vault")
+                .inner_size(420.0, 280.0)
+                .resizable(false)
+                .decorations(false)
+                .center()
+                .build()?;
+
+            WindowBuilder::new(app, "main", WindowUrl::App("index.html".into()))
+                .title("Mind Vault")
+                .inner_size(1100.0, 720.0)
+                .visible(false)
+                .build()?;
+
+            let accelerator = hotkey::load_persisted_accelerator(&handle);
+            hotkey::bind_accelerator(&handle, &accelerator)?;
+
+            tauri::async_runtime::spawn(async move {
+                index::run_startup_scan(&handle).await;
+
+                if let Some(splashscreen) = handle.get_window("splashscreen") {
+                    let _ = splashscreen.close();
+                }
+                if let Some(main_window) = handle.get_window("main") {
+                    let _ = main_window.show();
+                    let _ = main_window.set_focus();
+                }
+            });
+
+            let updater_handle = app.handle();
+            tauri::async_runtime::spawn(async move {
+                let _ = updater::check(&updater_handle, false).await;
+            });
+
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            get_app_data_dir,
+            hotkey::register_global_hotkey,
+            hotkey::unregister_global_hotkey,
+            hotkey::hide_window,
+            index::get_vault_index,
+            menu::confirm_close,
+            vault::save_document,
+            vault::load_document,
+            vault::list_documents,
+            vault::delete_document,
+            updater::check_for_updates,
+            updater::install_update
+        ])
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            if let RunEvent::ExitRequested { .. } = event {
+                let _ = index::flush_to_disk(app_handle);
+            }
+        });
 }
@@ -0,0 +1,95 @@
+//! Native application menu and the close-confirmation flow that keeps a
+//! user from losing an in-progress note by accidentally closing the window.
+
+use tauri::{
+    AppHandle, CustomMenuItem, GlobalWindowEvent, Manager, Menu, MenuItem, Submenu,
+    WindowEvent, WindowMenuEvent,
+};
+
+/// Menu item id for "Quit". Handled specially in [`handle_menu_event`] so it
+/// goes through the same unsaved-notes check as the tray's "Quit" item,
+/// rather than the native `MenuItem::Quit`, which exits the process
+/// directly and would bypass that check entirely.
+pub const QUIT_MENU_ID: &str = "quit";
+
+/// Builds the File / Edit / View menu, plus the platform app menu on macOS.
+pub fn build_menu() -> Menu {
+    let file_menu = Submenu::new(
+        "File",
+        Menu::new()
+            .add_item(CustomMenuItem::new("new-note", "New Note").accelerator("CmdOrCtrl+N"))
+            .add_item(CustomMenuItem::new("open-vault", "Open Vault").accelerator("CmdOrCtrl+O"))
+            .add_item(CustomMenuItem::new("save", "Save").accelerator("CmdOrCtrl+S")),
+    );
+
+    let edit_menu = Submenu::new(
+        "Edit",
+        Menu::new()
+            .add_native_item(MenuItem::Undo)
+            .add_native_item(MenuItem::Redo)
+            .add_native_item(MenuItem::Separator)
+            .add_native_item(MenuItem::Cut)
+            .add_native_item(MenuItem::Copy)
+            .add_native_item(MenuItem::Paste)
+            .add_native_item(MenuItem::SelectAll),
+    );
+
+    let view_menu = Submenu::new(
+        "View",
+        Menu::new().add_native_item(MenuItem::EnterFullScreen),
+    );
+
+    let mut menu = Menu::new();
+
+    #[cfg(target_os = "macos")]
+    {
+        menu = menu.add_submenu(Submenu::new(
+            "Mind Vault",
+            Menu::new()
+                .add_native_item(MenuItem::About("Mind Vault".to_string(), Default::default()))
+                .add_native_item(MenuItem::Separator)
+                .add_native_item(MenuItem::Services)
+                .add_native_item(MenuItem::Separator)
+                .add_native_item(MenuItem::Hide)
+                .add_native_item(MenuItem::HideOthers)
+                .add_native_item(MenuItem::ShowAll)
+                .add_native_item(MenuItem::Separator)
+                .add_item(CustomMenuItem::new(QUIT_MENU_ID, "Quit Mind Vault").accelerator("Cmd+Q")),
+        ));
+    }
+
+    menu.add_submenu(file_menu)
+        .add_submenu(edit_menu)
+        .add_submenu(view_menu)
+}
+
+/// Forwards a menu click to the frontend as a `menu://<id>` event, except
+/// for "Quit" which emits `quit-requested` instead so it runs through the
+/// same unsaved-notes confirmation as the tray's "Quit" item.
+pub fn handle_menu_event(event: WindowMenuEvent) {
+    let id = event.menu_item_id();
+    if id == QUIT_MENU_ID {
+        let _ = event.window().emit("quit-requested", ());
+        return;
+    }
+    let _ = event.window().emit(&format!("menu://{}", id), ());
+}
+
+/// Intercepts the window close request and hides the window to the tray
+/// instead of letting it close, so the app and its in-memory index keep
+/// running in the background.
+pub fn handle_window_event(event: GlobalWindowEvent) {
+    if let WindowEvent::CloseRequested { api, .. } = event.event() {
+        api.prevent_close();
+        let _ = event.window().hide();
+    }
+}
+
+/// Called by the frontend once it has confirmed there are no unsaved notes,
+/// in response to a `quit-requested` event. Actually exits the process.
+#[tauri::command]
+pub async fn confirm_close(app: AppHandle) -> Result<(), String> {
+    let _ = crate::index::flush_to_disk(&app);
+    app.exit(0);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! System tray icon that keeps the vault running as a background companion:
+//! closing the window hides it to the tray instead of quitting, and the
+//! tray menu offers quick access back into the app.
+
+use tauri::{
+    AppHandle, CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu,
+};
+
+use crate::hotkey;
+use crate::menu::QUIT_MENU_ID;
+
+const SHOW_VAULT: &str = "show-vault";
+const NEW_QUICK_NOTE: &str = "new-quick-note";
+
+pub fn build_tray() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new(SHOW_VAULT, "Show Vault"))
+        .add_item(CustomMenuItem::new(NEW_QUICK_NOTE, "New Quick Note"))
+        .add_item(CustomMenuItem::new(QUIT_MENU_ID, "Quit"));
+
+    SystemTray::new().with_menu(menu)
+}
+
+fn show_main_window(app: &AppHandle) -> Result<(), String> {
+    let window = app
+        .get_window("main")
+        .ok_or_else(|| "main window not found".to_string())?;
+    window.show().map_err(|e| e.to_string())?;
+    window.set_focus().map_err(|e| e.to_string())
+}
+
+pub fn handle_tray_event(app: &AppHandle, event: SystemTrayEvent) {
+    match event {
+        SystemTrayEvent::LeftClick { .. } => {
+            let _ = hotkey::toggle_main_window(app);
+        }
+        SystemTrayEvent::MenuItemClick { id, .. } => match id.as_str() {
+            SHOW_VAULT => {
+                let _ = show_main_window(app);
+            }
+            NEW_QUICK_NOTE => {
+                if show_main_window(app).is_ok() {
+                    if let Some(window) = app.get_window("main") {
+                        let _ = window.emit("quick-note", ());
+                    }
+                }
+            }
+            QUIT_MENU_ID => {
+                if let Some(window) = app.get_window("main") {
+                    let _ = window.emit("quit-requested", ());
+                } else {
+                    let _ = crate::index::flush_to_disk(app);
+                    app.exit(0);
+                }
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+}
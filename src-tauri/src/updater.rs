@@ -0,0 +1,108 @@
+//! Auto-update subsystem. Tauri's built-in updater already verifies the
+//! downloaded artifact's signature against the public key in
+//! `tauri.conf.json` before installing, so a compromised endpoint can't
+//! push an unsigned or tampered binary.
+
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tauri::{AppHandle, Manager};
+
+const LAST_CHECKED_FILE: &str = "updater-last-checked.json";
+const CHECK_THROTTLE_SECS: u64 = 6 * 60 * 60;
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn last_checked_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Could not get app data directory".to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(LAST_CHECKED_FILE))
+}
+
+fn read_last_checked(app: &AppHandle) -> u64 {
+    last_checked_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .and_then(|value| value.get("last_checked").and_then(|v| v.as_u64()))
+        .unwrap_or(0)
+}
+
+fn write_last_checked(app: &AppHandle) -> Result<(), String> {
+    let path = last_checked_path(app)?;
+    let contents = serde_json::json!({ "last_checked": now() }).to_string();
+    fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+/// Checks the remote release endpoint for a newer version, emitting
+/// `update-available` to the frontend when one is found. No-op if the last
+/// check ran within [`CHECK_THROTTLE_SECS`], unless `force` is set (used by
+/// the on-demand `check_for_updates` command).
+pub async fn check(app: &AppHandle, force: bool) -> Result<bool, String> {
+    if !force && now().saturating_sub(read_last_checked(app)) < CHECK_THROTTLE_SECS {
+        return Ok(false);
+    }
+
+    let response = app.updater().check().await.map_err(|e| e.to_string())?;
+    write_last_checked(app)?;
+
+    let available = response.is_update_available();
+    if available {
+        let _ = app.emit_all(
+            "update-available",
+            serde_json::json!({
+                "version": response.latest_version(),
+                "body": response.body(),
+            }),
+        );
+    }
+    Ok(available)
+}
+
+/// Downloads and installs the pending update, reporting progress to the
+/// frontend as it goes. Tauri verifies the artifact's signature as part of
+/// `download_and_install` and refuses to proceed if it doesn't match.
+pub async fn install(app: &AppHandle) -> Result<(), String> {
+    let response = app.updater().check().await.map_err(|e| e.to_string())?;
+    if !response.is_update_available() {
+        return Err("no update available".to_string());
+    }
+
+    let app_handle = app.clone();
+    let app_handle_finished = app.clone();
+    response
+        .download_and_install(
+            move |chunk_length, content_length| {
+                let _ = app_handle.emit_all(
+                    "update-progress",
+                    serde_json::json!({
+                        "chunkLength": chunk_length,
+                        "contentLength": content_length,
+                    }),
+                );
+            },
+            move || {
+                let _ = app_handle_finished.emit_all("update-progress", serde_json::json!({ "finished": true }));
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle) -> Result<bool, String> {
+    check(&app, true).await
+}
+
+#[tauri::command]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    install(&app).await
+}
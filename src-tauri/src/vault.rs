@@ -0,0 +1,273 @@
+//! File-backed persistence for vault documents.
+//!
+//! Each note is stored as a single Markdown file inside `vault/` with a YAML
+//! front-matter block holding its metadata, e.g.:
+//!
+//! ```text
+//! ---
+//! tags: [recipe]
+//! created: 1700000000
+//! modified: 1700000100
+//! ---
+//! # Body starts here
+//! ```
+//!
+//! Writes go to a temp file in the same directory and are then renamed over
+//! the target, so a crash mid-save leaves the previous version intact
+//! instead of a half-written note.
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Manager};
+
+const VAULT_DIR: &str = "vault";
+const FRONT_MATTER_DELIMITER: &str = "---";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Document {
+    pub id: String,
+    pub content: String,
+    pub metadata: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentSummary {
+    pub id: String,
+    pub modified: u64,
+    pub metadata: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FrontMatter {
+    #[serde(flatten)]
+    metadata: Value,
+    created: u64,
+    modified: u64,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn vault_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Could not get app data directory".to_string())?
+        .join(VAULT_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// Rejects anything but a simple slug: ASCII letters, digits, `-` and `_`.
+/// `id` comes straight from the webview over IPC, so this is the only thing
+/// standing between a note id and a path-traversal write/read/delete
+/// outside the vault directory (e.g. `../../../../Library/LaunchAgents/evil`).
+fn validate_id(id: &str) -> Result<(), String> {
+    let valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("invalid document id: {}", id))
+    }
+}
+
+fn document_path(dir: &Path, id: &str) -> Result<PathBuf, String> {
+    validate_id(id)?;
+    Ok(dir.join(format!("{}.md", id)))
+}
+
+fn serialize_document(content: &str, front_matter: &FrontMatter) -> Result<String, String> {
+    let yaml = serde_yaml::to_string(front_matter).map_err(|e| e.to_string())?;
+    Ok(format!(
+        "{delim}\n{yaml}{delim}\n{content}",
+        delim = FRONT_MATTER_DELIMITER,
+        yaml = yaml,
+        content = content
+    ))
+}
+
+/// Splits a stored document into its raw front-matter YAML and body.
+pub(crate) fn split_front_matter(raw: &str) -> Result<(&str, &str), String> {
+    let rest = raw
+        .strip_prefix(FRONT_MATTER_DELIMITER)
+        .ok_or_else(|| "document is missing front matter".to_string())?;
+    let end = rest
+        .find(FRONT_MATTER_DELIMITER)
+        .ok_or_else(|| "document has unterminated front matter".to_string())?;
+    let body = rest[end + FRONT_MATTER_DELIMITER.len()..].trim_start_matches('\n');
+    Ok((&rest[..end], body))
+}
+
+/// Returns just the Markdown body, stripping the front-matter block if
+/// present. Falls back to the raw contents for files that predate the
+/// front-matter format, rather than erroring.
+pub(crate) fn body_only(raw: &str) -> &str {
+    split_front_matter(raw).map(|(_, body)| body).unwrap_or(raw)
+}
+
+fn read_front_matter(raw: &str) -> Result<FrontMatter, String> {
+    let (yaml, _) = split_front_matter(raw)?;
+    serde_yaml::from_str(yaml).map_err(|e| e.to_string())
+}
+
+fn parse_document(id: &str, raw: &str) -> Result<Document, String> {
+    let (yaml, body) = split_front_matter(raw)?;
+    let front_matter: FrontMatter = serde_yaml::from_str(yaml).map_err(|e| e.to_string())?;
+
+    Ok(Document {
+        id: id.to_string(),
+        content: body.to_string(),
+        metadata: front_matter.metadata,
+    })
+}
+
+/// Writes `content` to a temp file in `dir` and renames it over `target`,
+/// so readers only ever see a fully-written file.
+fn atomic_write(dir: &Path, target: &Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let temp_path = dir.join(format!(".{}.tmp", uuid_like()));
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temp_path)?
+        .write_all(contents.as_bytes())?;
+    std::fs::rename(&temp_path, target)
+}
+
+/// Cheap unique suffix for the temp file name; collisions would only cause
+/// a retry-able write failure, never data loss, since the target path is
+/// untouched until the rename succeeds.
+fn uuid_like() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0)
+}
+
+#[tauri::command]
+pub async fn save_document(
+    app: AppHandle,
+    id: String,
+    content: String,
+    metadata: Value,
+) -> Result<(), String> {
+    let dir = vault_dir(&app)?;
+    let path = document_path(&dir, &id)?;
+
+    let created = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|existing| read_front_matter(&existing).ok())
+        .map(|fm| fm.created)
+        .unwrap_or_else(now);
+
+    let front_matter = FrontMatter { metadata, created, modified: now() };
+    let serialized = serialize_document(&content, &front_matter)?;
+    atomic_write(&dir, &path, &serialized).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn load_document(app: AppHandle, id: String) -> Result<Document, String> {
+    let dir = vault_dir(&app)?;
+    let path = document_path(&dir, &id)?;
+    let raw = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    parse_document(&id, &raw)
+}
+
+#[tauri::command]
+pub async fn list_documents(app: AppHandle) -> Result<Vec<DocumentSummary>, String> {
+    let dir = vault_dir(&app)?;
+    let mut summaries = Vec::new();
+
+    for entry in std::fs::read_dir(&dir).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+            continue;
+        }
+        let Some(id) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let raw = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        if let Ok(front_matter) = read_front_matter(&raw) {
+            summaries.push(DocumentSummary {
+                id: id.to_string(),
+                modified: front_matter.modified,
+                metadata: front_matter.metadata,
+            });
+        }
+    }
+
+    Ok(summaries)
+}
+
+#[tauri::command]
+pub async fn delete_document(app: AppHandle, id: String) -> Result<(), String> {
+    let dir = vault_dir(&app)?;
+    let path = document_path(&dir, &id)?;
+    std::fs::remove_file(&path).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_content_and_metadata_through_front_matter() {
+        let front_matter = FrontMatter {
+            metadata: serde_json::json!({ "tags": ["recipe"] }),
+            created: 1,
+            modified: 2,
+        };
+        let serialized = serialize_document("# Hello\nbody text", &front_matter).unwrap();
+
+        let document = parse_document("some-id", &serialized).unwrap();
+        assert_eq!(document.id, "some-id");
+        assert_eq!(document.content, "# Hello\nbody text");
+        assert_eq!(document.metadata, serde_json::json!({ "tags": ["recipe"] }));
+
+        let parsed_front_matter = read_front_matter(&serialized).unwrap();
+        assert_eq!(parsed_front_matter.created, 1);
+        assert_eq!(parsed_front_matter.modified, 2);
+    }
+
+    #[test]
+    fn rejects_missing_front_matter() {
+        let err = parse_document("some-id", "# Hello\nbody text").unwrap_err();
+        assert!(err.contains("missing front matter"));
+    }
+
+    #[test]
+    fn rejects_unterminated_front_matter() {
+        let err = parse_document("some-id", "---\ncreated: 1\nmodified: 2\n").unwrap_err();
+        assert!(err.contains("unterminated front matter"));
+    }
+
+    #[test]
+    fn rejects_non_object_metadata() {
+        let front_matter = FrontMatter {
+            metadata: serde_json::json!("not-an-object"),
+            created: 1,
+            modified: 2,
+        };
+        assert!(serialize_document("body", &front_matter).is_err());
+    }
+
+    #[test]
+    fn validates_document_ids() {
+        assert!(validate_id("my-note_123").is_ok());
+        assert!(validate_id("").is_err());
+        assert!(validate_id("../../etc/passwd").is_err());
+        assert!(validate_id("a/b").is_err());
+    }
+}